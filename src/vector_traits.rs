@@ -14,9 +14,25 @@
 // limitations under the License.
 
 
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "mint")]
+extern crate mint;
+
+#[cfg(feature = "nalgebra")]
 use nalgebra as na;
+#[cfg(feature = "cgmath")]
 use cgmath as cg;
+#[cfg(feature = "nalgebra")]
 use nalgebra::{Repeat};
+#[cfg(feature = "glam")]
+use glam;
+#[cfg(feature = "mint")]
+use mint;
 
 use std::fmt::Debug;
 use traits::SpadeNum;
@@ -24,10 +40,12 @@ use num::{zero};
 use misc::{min_inline, max_inline};
 
 /// Abstraction over vectors with a fixed number of dimensions.
-/// Spade will work with any vector type implementing this trait, at the
-/// moment vectors of the `cgmath` and `nalgebra` crates are supported.
-/// Also, the trait is implemented for fixed arrays of length 2, 3 and 4, allowing
-/// to use spade's datastructures with fixed size arrays as point coordinates.
+/// Spade will work with any vector type implementing this trait. Vectors of
+/// the `cgmath`, `nalgebra`, `glam` and `mint` crates are supported behind
+/// the `cgmath`, `nalgebra`, `glam` and `mint` Cargo features, respectively.
+/// Also, the trait is implemented for fixed size arrays `[S; N]` of any
+/// length, allowing to use spade's datastructures with fixed size arrays
+/// of arbitrary dimensionality as point coordinates.
 /// That means that the trait's methods are also implemented for
 /// these array types, thus be careful when importing `VectorN`.
 ///
@@ -47,6 +65,10 @@ pub trait VectorN
     /// Creates a new vector with all compoenents set to a certain value.
     fn from_value(value: Self::Scalar) -> Self;
 
+    /// Constructs a new vector, setting the component at index `i` to the
+    /// result of calling `f(i)`.
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self;
+
     /// Returns the nth element of this vector.
     fn nth(&self, index: usize) -> &Self::Scalar;
     /// Returns a mutable reference to the nth element of this vector.
@@ -57,7 +79,7 @@ pub trait VectorN
 pub trait VectorNExtensions : VectorN {
     /// Creates a new vector with all components initialized to zero.
     fn new() -> Self {
-        Self::from_value(zero())
+        Self::generate(|_| zero())
     }
 
     /// Adds two vectors.
@@ -83,20 +105,12 @@ pub trait VectorNExtensions : VectorN {
 
     /// Applies a binary operation component wise.
     fn component_wise<F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar>(&self, rhs: &Self, f: F) -> Self {
-        let mut result = self.clone();
-        for i in 0 .. Self::dimensions() {
-            *result.nth_mut(i) = f(self.nth(i).clone(), rhs.nth(i).clone());
-        }
-        result
+        Self::generate(|i| f(self.nth(i).clone(), rhs.nth(i).clone()))
     }
 
     /// Maps an unary operation to all compoenents.
     fn map<F: Fn(Self::Scalar) -> O::Scalar, O: VectorN>(&self, f: F) -> O {
-        let mut result = O::new();
-        for i in 0 .. Self::dimensions() {
-            *result.nth_mut(i)  = f(self.nth(i).clone());
-        }
-        result
+        O::generate(|i| f(self.nth(i).clone()))
     }
 
     /// Returns a new vector containing the minimum values of this and another vector (componentwise)
@@ -145,9 +159,11 @@ impl <T> VectorNExtensions for T where T: VectorN { }
 /// this trait makes sure that only such vectors can be passed.
 pub trait TwoDimensional : VectorN { }
 
+#[cfg(feature = "cgmath")]
 impl <S: SpadeNum + cg::BaseNum> TwoDimensional for cg::Vector2<S> { }
+#[cfg(feature = "nalgebra")]
 impl <S: SpadeNum + na::BaseNum> TwoDimensional for na::Vector2<S> { }
-impl <S: SpadeNum + Copy> TwoDimensional for [S; 2] { }
+impl <S: SpadeNum + Clone> TwoDimensional for [S; 2] { }
 
 /// A three dimensional Vector.
 /// Some algorithms will only work with three dimensional vectors, this trait makes
@@ -166,49 +182,32 @@ pub trait ThreeDimensional : VectorN {
     }
 }
 
+#[cfg(feature = "cgmath")]
 impl <S: SpadeNum + cg::BaseNum> ThreeDimensional for cg::Vector3<S> { }
 
+#[cfg(feature = "nalgebra")]
 impl <S: SpadeNum + na::BaseNum> ThreeDimensional for na::Vector3<S> { }
 
-impl <S: SpadeNum + Copy> ThreeDimensional for [S; 3] { }
+impl <S: SpadeNum + Clone> ThreeDimensional for [S; 3] { }
 
-impl <S: SpadeNum + Copy> VectorN for [S; 2] {
+impl <S: SpadeNum + Clone, const N: usize> VectorN for [S; N] {
     type Scalar = S;
-    fn dimensions() -> usize { 2 }
-
-    fn nth(&self, index: usize) -> &S { &self[index] }
-    fn nth_mut(&mut self, index: usize) -> &mut S { &mut self[index] }
-    
-    fn from_value(value: Self::Scalar) -> Self {
-        [value; 2]
-    }
-}
 
-impl <S: SpadeNum + Copy> VectorN for [S; 3] {
-    type Scalar = S;
-    fn dimensions() -> usize { 3 }
+    fn dimensions() -> usize { N }
 
     fn nth(&self, index: usize) -> &S { &self[index] }
     fn nth_mut(&mut self, index: usize) -> &mut S { &mut self[index] }
-    
+
     fn from_value(value: Self::Scalar) -> Self {
-        [value; 3]
+        Self::generate(|_| value.clone())
     }
-}
-
-impl <S: SpadeNum + Copy> VectorN for [S; 4] {
-    type Scalar = S;
-    
-    fn dimensions() -> usize { 4 }
 
-    fn nth(&self, index: usize) -> &S { &self[index] }
-    fn nth_mut(&mut self, index: usize) -> &mut S { &mut self[index] }
-    
-    fn from_value(value: Self::Scalar) -> Self {
-        [value; 4]
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        std::array::from_fn(f)
     }
 }
 
+#[cfg(feature = "cgmath")]
 impl<S: SpadeNum + cg::BaseNum> VectorN for cg::Vector2<S> {
     type Scalar = S;
     
@@ -220,11 +219,16 @@ impl<S: SpadeNum + cg::BaseNum> VectorN for cg::Vector2<S> {
     fn from_value(value: Self::Scalar) -> Self {
         cg::Array::from_value(value)
     }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        cg::Vector2::new(f(0), f(1))
+    }
 }
 
+#[cfg(feature = "cgmath")]
 impl<S: SpadeNum + cg::BaseNum> VectorN for cg::Vector3<S> {
     type Scalar = S;
-    
+
     fn dimensions() -> usize { 3 }
 
     fn nth(&self, index: usize) -> &S { &self[index] }
@@ -233,11 +237,16 @@ impl<S: SpadeNum + cg::BaseNum> VectorN for cg::Vector3<S> {
     fn from_value(value: Self::Scalar) -> Self {
         cg::Array::from_value(value)
     }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        cg::Vector3::new(f(0), f(1), f(2))
+    }
 }
 
+#[cfg(feature = "cgmath")]
 impl<S: SpadeNum + cg::BaseNum> VectorN for cg::Vector4<S> {
     type Scalar = S;
-    
+
     fn dimensions() -> usize { 4 }
 
     fn nth(&self, index: usize) -> &S { &self[index] }
@@ -246,8 +255,13 @@ impl<S: SpadeNum + cg::BaseNum> VectorN for cg::Vector4<S> {
     fn from_value(value: Self::Scalar) -> Self {
         cg::Array::from_value(value)
     }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        cg::Vector4::new(f(0), f(1), f(2), f(3))
+    }
 }
 
+#[cfg(feature = "nalgebra")]
 impl<S: SpadeNum + na::BaseNum> VectorN for na::Vector2<S> {
     type Scalar = S;
     
@@ -259,11 +273,16 @@ impl<S: SpadeNum + na::BaseNum> VectorN for na::Vector2<S> {
     fn from_value(value: Self::Scalar) -> Self {
         na::Vector2::repeat(value)
     }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        na::Vector2::new(f(0), f(1))
+    }
 }
 
+#[cfg(feature = "nalgebra")]
 impl<S: SpadeNum + na::BaseNum> VectorN for na::Vector3<S> {
     type Scalar = S;
-    
+
     fn dimensions() -> usize { 3 }
 
     fn nth(&self, index: usize) -> &S { &self[index] }
@@ -272,11 +291,16 @@ impl<S: SpadeNum + na::BaseNum> VectorN for na::Vector3<S> {
     fn from_value(value: Self::Scalar) -> Self {
         na::Vector3::repeat(value)
     }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        na::Vector3::new(f(0), f(1), f(2))
+    }
 }
 
+#[cfg(feature = "nalgebra")]
 impl<S: SpadeNum + na::BaseNum> VectorN for na::Vector4<S> {
     type Scalar = S;
-    
+
     fn dimensions() -> usize { 4 }
 
     fn nth(&self, index: usize) -> &S { &self[index] }
@@ -285,4 +309,253 @@ impl<S: SpadeNum + na::BaseNum> VectorN for na::Vector4<S> {
     fn from_value(value: Self::Scalar) -> Self {
         na::Vector4::repeat(value)
     }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        na::Vector4::new(f(0), f(1), f(2), f(3))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl TwoDimensional for glam::Vec2 { }
+#[cfg(feature = "glam")]
+impl TwoDimensional for glam::DVec2 { }
+#[cfg(feature = "glam")]
+impl TwoDimensional for glam::IVec2 { }
+
+#[cfg(feature = "glam")]
+impl ThreeDimensional for glam::Vec3 { }
+#[cfg(feature = "glam")]
+impl ThreeDimensional for glam::DVec3 { }
+#[cfg(feature = "glam")]
+impl ThreeDimensional for glam::IVec3 { }
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::Vec2 {
+    type Scalar = f32;
+
+    fn dimensions() -> usize { 2 }
+
+    fn nth(&self, index: usize) -> &f32 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut f32 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::Vec2::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::Vec2::new(f(0), f(1))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::Vec3 {
+    type Scalar = f32;
+
+    fn dimensions() -> usize { 3 }
+
+    fn nth(&self, index: usize) -> &f32 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut f32 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::Vec3::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::Vec3::new(f(0), f(1), f(2))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::Vec4 {
+    type Scalar = f32;
+
+    fn dimensions() -> usize { 4 }
+
+    fn nth(&self, index: usize) -> &f32 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut f32 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::Vec4::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::Vec4::new(f(0), f(1), f(2), f(3))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::DVec2 {
+    type Scalar = f64;
+
+    fn dimensions() -> usize { 2 }
+
+    fn nth(&self, index: usize) -> &f64 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut f64 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::DVec2::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::DVec2::new(f(0), f(1))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::DVec3 {
+    type Scalar = f64;
+
+    fn dimensions() -> usize { 3 }
+
+    fn nth(&self, index: usize) -> &f64 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut f64 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::DVec3::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::DVec3::new(f(0), f(1), f(2))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::DVec4 {
+    type Scalar = f64;
+
+    fn dimensions() -> usize { 4 }
+
+    fn nth(&self, index: usize) -> &f64 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut f64 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::DVec4::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::DVec4::new(f(0), f(1), f(2), f(3))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::IVec2 {
+    type Scalar = i32;
+
+    fn dimensions() -> usize { 2 }
+
+    fn nth(&self, index: usize) -> &i32 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut i32 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::IVec2::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::IVec2::new(f(0), f(1))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::IVec3 {
+    type Scalar = i32;
+
+    fn dimensions() -> usize { 3 }
+
+    fn nth(&self, index: usize) -> &i32 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut i32 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::IVec3::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::IVec3::new(f(0), f(1), f(2))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl VectorN for glam::IVec4 {
+    type Scalar = i32;
+
+    fn dimensions() -> usize { 4 }
+
+    fn nth(&self, index: usize) -> &i32 { &self[index] }
+    fn nth_mut(&mut self, index: usize) -> &mut i32 { &mut self[index] }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        glam::IVec4::splat(value)
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        glam::IVec4::new(f(0), f(1), f(2), f(3))
+    }
+}
+
+#[cfg(feature = "mint")]
+impl <S: SpadeNum + Clone> TwoDimensional for mint::Vector2<S> { }
+#[cfg(feature = "mint")]
+impl <S: SpadeNum + Clone> ThreeDimensional for mint::Vector3<S> { }
+
+#[cfg(feature = "mint")]
+impl <S: SpadeNum + Clone> VectorN for mint::Vector2<S> {
+    type Scalar = S;
+
+    fn dimensions() -> usize { 2 }
+
+    fn nth(&self, index: usize) -> &S {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("nth: index out of bounds"),
+        }
+    }
+
+    fn nth_mut(&mut self, index: usize) -> &mut S {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("nth_mut: index out of bounds"),
+        }
+    }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        Self::generate(|_| value.clone())
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        mint::Vector2 { x: f(0), y: f(1) }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl <S: SpadeNum + Clone> VectorN for mint::Vector3<S> {
+    type Scalar = S;
+
+    fn dimensions() -> usize { 3 }
+
+    fn nth(&self, index: usize) -> &S {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("nth: index out of bounds"),
+        }
+    }
+
+    fn nth_mut(&mut self, index: usize) -> &mut S {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("nth_mut: index out of bounds"),
+        }
+    }
+
+    fn from_value(value: Self::Scalar) -> Self {
+        Self::generate(|_| value.clone())
+    }
+
+    fn generate<F: Fn(usize) -> Self::Scalar>(f: F) -> Self {
+        mint::Vector3 { x: f(0), y: f(1), z: f(2) }
+    }
 }